@@ -0,0 +1,266 @@
+//! Calendar of business days addressed by proleptic-Gregorian ordinals.
+
+/// Zero-based weekday (Monday = 0 .. Sunday = 6) for a proleptic Gregorian
+/// ordinal, using the same epoch as `date.toordinal()` (ordinal 1 is
+/// 0001-01-01, a Monday).
+pub(crate) fn weekday(ordinal: i32) -> usize {
+    (ordinal - 1).rem_euclid(7) as usize
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+const DAYS_IN_MONTH: [i32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+/// Number of days in `month` (1-based) of `year`.
+pub(crate) fn days_in_month(year: i32, month: u32) -> i32 {
+    if month == 2 && is_leap_year(year) {
+        29
+    } else {
+        DAYS_IN_MONTH[(month - 1) as usize]
+    }
+}
+
+fn days_before_year(year: i32) -> i32 {
+    let y = year - 1;
+    y * 365 + y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400)
+}
+
+/// Proleptic Gregorian ordinal for a year/month/day, using the same epoch
+/// as `date.toordinal()` (0001-01-01 is ordinal 1).
+pub(crate) fn ymd_to_ordinal(year: i32, month: u32, day: u32) -> i32 {
+    let mut days_before_month = 0;
+    for m in 1..month {
+        days_before_month += days_in_month(year, m);
+    }
+    days_before_year(year) + days_before_month + day as i32
+}
+
+/// Inverse of [`ymd_to_ordinal`]: the year/month/day for a proleptic
+/// Gregorian ordinal.
+pub(crate) fn ordinal_to_ymd(ordinal: i32) -> (i32, u32, u32) {
+    let mut year = ordinal / 365;
+    while days_before_year(year + 1) < ordinal {
+        year += 1;
+    }
+    while days_before_year(year) >= ordinal {
+        year -= 1;
+    }
+
+    let mut day_of_year = ordinal - days_before_year(year);
+    let mut month = 1u32;
+    loop {
+        let dim = days_in_month(year, month);
+        if day_of_year <= dim {
+            break;
+        }
+        day_of_year -= dim;
+        month += 1;
+    }
+    (year, month, day_of_year as u32)
+}
+
+/// A calendar of business days built from a candidate set of ordinals.
+///
+/// `BusinessCalendar` takes the ordinals a caller wants considered (for
+/// example every day spanning a date range), a `week_mask` selecting which
+/// weekdays (Monday..Sunday) are ever eligible to be business days, and a
+/// list of holiday ordinals to exclude on top of that. Callers no longer
+/// need to pre-filter weekends or holidays themselves.
+pub struct BusinessCalendar {
+    days: Vec<i32>,
+    week_mask: [bool; 7],
+    holidays: Vec<i32>,
+    n_bdays: usize,
+}
+
+impl BusinessCalendar {
+    /// Build a calendar from `ordinals` filtered by `week_mask` and
+    /// `holidays`. `week_mask` is indexed Monday..Sunday; a `false` entry
+    /// means that weekday is never a business day, regardless of holidays.
+    pub fn new(ordinals: Vec<i32>, week_mask: [bool; 7], holidays: &[i32]) -> Self {
+        let mut holidays: Vec<i32> = holidays
+            .iter()
+            .copied()
+            .filter(|&h| week_mask[weekday(h)])
+            .collect();
+        holidays.sort_unstable();
+        holidays.dedup();
+
+        let mut days: Vec<i32> = ordinals
+            .into_iter()
+            .filter(|&o| week_mask[weekday(o)] && holidays.binary_search(&o).is_err())
+            .collect();
+        days.sort_unstable();
+        days.dedup();
+
+        let n_bdays = week_mask.iter().filter(|m| **m).count();
+
+        BusinessCalendar {
+            days,
+            week_mask,
+            holidays,
+            n_bdays,
+        }
+    }
+
+    pub fn is_business_day(&self, ordinal: i32) -> bool {
+        self.days.binary_search(&ordinal).is_ok()
+    }
+
+    pub fn add_business_days(&self, ordinal: i32, n: i32) -> Option<i32> {
+        let idx = match self.days.binary_search(&ordinal) {
+            Ok(i) | Err(i) => i,
+        };
+        let target = idx as i64 + n as i64;
+        if target < 0 {
+            return None;
+        }
+        self.days.get(target as usize).copied()
+    }
+
+    pub fn next_business_day(&self, ordinal: i32) -> Option<i32> {
+        match self.days.binary_search(&ordinal) {
+            Ok(i) | Err(i) => self.days.get(i).copied(),
+        }
+    }
+
+    pub fn prev_business_day(&self, ordinal: i32) -> Option<i32> {
+        match self.days.binary_search(&ordinal) {
+            Ok(i) => self.days.get(i).copied(),
+            Err(i) => {
+                if i == 0 {
+                    None
+                } else {
+                    self.days.get(i - 1).copied()
+                }
+            }
+        }
+    }
+
+    pub fn business_days_in_range(&self, start: i32, end: i32) -> Vec<i32> {
+        if end <= start {
+            return Vec::new();
+        }
+        let lo = self.days.partition_point(|&d| d < start);
+        let hi = self.days.partition_point(|&d| d < end);
+        self.days[lo..hi].to_vec()
+    }
+
+    pub fn count_business_days(&self, start: i32, end: i32) -> usize {
+        if end <= start {
+            return 0;
+        }
+        let lo = self.days.partition_point(|&d| d < start);
+        let hi = self.days.partition_point(|&d| d < end);
+        hi - lo
+    }
+
+    /// Count business days in `[start, end)` for many pairs at once.
+    ///
+    /// This is the closed-form algorithm Polars uses for
+    /// `business_day_count`: each pair is resolved in O(log H) against the
+    /// holiday list, without scanning the range. The count uses only the
+    /// week mask and the holidays, so unlike
+    /// [`count_business_days`](Self::count_business_days) it is not limited
+    /// to the ordinals the calendar was built from; the two agree for ranges
+    /// those ordinals fully cover. Pairs are resolved independently (no ordering assumption
+    /// on `starts`/`ends`) so a full binary search is done per pair rather
+    /// than carrying the holiday search window forward between pairs,
+    /// which would silently corrupt results for out-of-order pairs.
+    pub fn count_business_days_pairs(&self, starts: &[i32], ends: &[i32]) -> Vec<usize> {
+        starts
+            .iter()
+            .zip(ends.iter())
+            .map(|(&start, &end)| {
+                if end <= start {
+                    return 0;
+                }
+
+                let span = end - start;
+                let whole_weeks = span.div_euclid(7);
+                let remainder = span.rem_euclid(7);
+
+                let mut count = whole_weeks as i64 * self.n_bdays as i64;
+                for day in 0..remainder {
+                    if self.week_mask[weekday(start + day)] {
+                        count += 1;
+                    }
+                }
+
+                let lo = self.holidays.binary_search(&start).unwrap_or_else(|i| i);
+                let hi = self.holidays.binary_search(&end).unwrap_or_else(|i| i);
+                count -= (hi - lo) as i64;
+
+                count.max(0) as usize
+            })
+            .collect()
+    }
+
+    pub fn get_index(&self, ordinal: i32) -> Option<usize> {
+        self.days.binary_search(&ordinal).ok()
+    }
+
+    pub fn get_at_index(&self, index: usize) -> Option<i32> {
+        self.days.get(index).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.days.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.days.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_business_days_pairs_matches_scalar_for_unsorted_pairs() {
+        let week_mask = [true, true, true, true, true, false, false];
+        let holidays = [10, 50, 90];
+        let days: Vec<i32> = (0..100).collect();
+        let cal = BusinessCalendar::new(days, week_mask, &holidays);
+
+        // (80, 95) before (5, 15): out of order and overlapping the same
+        // holiday search window, which used to corrupt the second pair.
+        let starts = [80, 5];
+        let ends = [95, 15];
+        let batch = cal.count_business_days_pairs(&starts, &ends);
+        let scalar: Vec<usize> = starts
+            .iter()
+            .zip(ends.iter())
+            .map(|(&s, &e)| cal.count_business_days(s, e))
+            .collect();
+
+        assert_eq!(batch, scalar);
+    }
+
+    #[test]
+    fn count_business_days_and_range_clamp_on_reversed_bounds() {
+        let week_mask = [true, true, true, true, true, false, false];
+        let days: Vec<i32> = (0..100).collect();
+        let cal = BusinessCalendar::new(days, week_mask, &[]);
+
+        assert_eq!(cal.count_business_days(50, 10), 0);
+        assert!(cal.business_days_in_range(50, 10).is_empty());
+    }
+
+    #[test]
+    fn count_business_days_pairs_clamps_reversed_pair_with_holidays_in_range() {
+        let week_mask = [true, true, true, true, true, false, false];
+        let holidays: Vec<i32> = (3..15).collect();
+        let days: Vec<i32> = (0..100).collect();
+        let cal = BusinessCalendar::new(days, week_mask, &holidays);
+
+        // Reversed pair (14, 2): without the `end <= start` guard, the
+        // holiday insertion index of `end` (0) is below that of `start` (8),
+        // so the `hi - lo` holiday count underflows.
+        let batch = cal.count_business_days_pairs(&[14], &[2]);
+        assert_eq!(batch, vec![0]);
+    }
+}
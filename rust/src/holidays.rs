@@ -0,0 +1,165 @@
+//! Declarative named holiday calendars for [`BusinessCalendar`](crate::calendar::BusinessCalendar).
+//!
+//! Rather than hand-maintain a raw ordinal per holiday per year, a named
+//! market is described as a small set of [`HolidayRule`]s and expanded over
+//! a year range with [`generate`].
+
+use crate::calendar::{days_in_month, weekday, ymd_to_ordinal};
+
+/// A single holiday rule, resolved to an ordinal for a given year.
+#[derive(Clone, Copy)]
+pub enum HolidayRule {
+    /// A fixed month/day, e.g. Christmas (12, 25).
+    Fixed { month: u32, day: u32 },
+    /// The `nth` occurrence of `weekday` (Monday = 0 .. Sunday = 6) in
+    /// `month`. A negative `nth` counts from the end of the month, so `-1`
+    /// is "last Monday of May".
+    NthWeekday { month: u32, weekday: usize, nth: i32 },
+    /// A fixed offset in days from Easter Sunday, e.g. `-2` for Good
+    /// Friday or `1` for Easter Monday.
+    EasterOffset { offset: i32 },
+}
+
+/// Easter Sunday as an ordinal, via the Anonymous Gregorian Computus.
+fn easter_sunday(year: i32) -> i32 {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    ymd_to_ordinal(year, month as u32, day as u32)
+}
+
+impl HolidayRule {
+    fn resolve(&self, year: i32) -> i32 {
+        match *self {
+            HolidayRule::Fixed { month, day } => ymd_to_ordinal(year, month, day),
+            HolidayRule::NthWeekday {
+                month,
+                weekday: wd,
+                nth,
+            } => {
+                if nth > 0 {
+                    let first_of_month = ymd_to_ordinal(year, month, 1);
+                    let delta = (wd as i32 - weekday(first_of_month) as i32).rem_euclid(7);
+                    first_of_month + delta + (nth - 1) * 7
+                } else {
+                    let last_day = days_in_month(year, month);
+                    let last_of_month = ymd_to_ordinal(year, month, last_day as u32);
+                    let delta = (weekday(last_of_month) as i32 - wd as i32).rem_euclid(7);
+                    last_of_month - delta + (nth + 1) * 7
+                }
+            }
+            HolidayRule::EasterOffset { offset } => easter_sunday(year) + offset,
+        }
+    }
+}
+
+/// Rules for a named market's public holidays. Returns `None` for an
+/// unrecognized name.
+pub fn market_rules(name: &str) -> Option<&'static [HolidayRule]> {
+    use HolidayRule::{EasterOffset, Fixed, NthWeekday};
+
+    match name {
+        "US" => Some(&[
+            Fixed { month: 1, day: 1 },                           // New Year's Day
+            NthWeekday { month: 1, weekday: 0, nth: 3 },           // MLK Day
+            NthWeekday { month: 2, weekday: 0, nth: 3 },           // Presidents Day
+            NthWeekday { month: 5, weekday: 0, nth: -1 },          // Memorial Day
+            Fixed { month: 6, day: 19 },                           // Juneteenth
+            Fixed { month: 7, day: 4 },                            // Independence Day
+            NthWeekday { month: 9, weekday: 0, nth: 1 },           // Labor Day
+            NthWeekday { month: 11, weekday: 3, nth: 4 },          // Thanksgiving
+            Fixed { month: 12, day: 25 },                          // Christmas
+        ]),
+        "UK" => Some(&[
+            Fixed { month: 1, day: 1 },                           // New Year's Day
+            EasterOffset { offset: -2 },                          // Good Friday
+            EasterOffset { offset: 1 },                           // Easter Monday
+            NthWeekday { month: 5, weekday: 0, nth: 1 },           // Early May bank holiday
+            NthWeekday { month: 5, weekday: 0, nth: -1 },          // Spring bank holiday
+            NthWeekday { month: 8, weekday: 0, nth: -1 },          // Summer bank holiday
+            Fixed { month: 12, day: 25 },                          // Christmas Day
+            Fixed { month: 12, day: 26 },                          // Boxing Day
+        ]),
+        "Czech" => Some(&[
+            Fixed { month: 1, day: 1 },                           // Restoration Day
+            EasterOffset { offset: -2 },                          // Good Friday
+            EasterOffset { offset: 1 },                           // Easter Monday
+            EasterOffset { offset: 60 },                          // Corpus Christi
+            Fixed { month: 5, day: 1 },                            // Labour Day
+            Fixed { month: 5, day: 8 },                            // Liberation Day
+            Fixed { month: 7, day: 5 },                            // Cyril and Methodius Day
+            Fixed { month: 7, day: 6 },                            // Jan Hus Day
+            Fixed { month: 9, day: 28 },                           // Czech Statehood Day
+            Fixed { month: 10, day: 28 },                          // Independent Czechoslovak State Day
+            Fixed { month: 11, day: 17 },                          // Freedom and Democracy Day
+            Fixed { month: 12, day: 24 },                          // Christmas Eve
+            Fixed { month: 12, day: 25 },                          // Christmas Day
+            Fixed { month: 12, day: 26 },                          // St Stephen's Day
+        ]),
+        _ => None,
+    }
+}
+
+/// Expand a named market's holiday rules into ordinals over
+/// `start_year..=end_year`. Returns `None` for an unrecognized market name.
+pub fn generate(name: &str, start_year: i32, end_year: i32) -> Option<Vec<i32>> {
+    let rules = market_rules(name)?;
+    let mut ordinals: Vec<i32> = (start_year..=end_year)
+        .flat_map(|year| rules.iter().map(move |rule| rule.resolve(year)))
+        .collect();
+    ordinals.sort_unstable();
+    ordinals.dedup();
+    Some(ordinals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easter_sunday_matches_known_dates() {
+        assert_eq!(easter_sunday(2024), ymd_to_ordinal(2024, 3, 31));
+        assert_eq!(easter_sunday(2025), ymd_to_ordinal(2025, 4, 20));
+        assert_eq!(easter_sunday(2026), ymd_to_ordinal(2026, 4, 5));
+    }
+
+    #[test]
+    fn nth_weekday_from_end_of_month_matches_us_memorial_day() {
+        // Memorial Day is the last Monday of May; 2026's falls on the 25th.
+        let rule = HolidayRule::NthWeekday {
+            month: 5,
+            weekday: 0,
+            nth: -1,
+        };
+        assert_eq!(rule.resolve(2026), ymd_to_ordinal(2026, 5, 25));
+        // 2025's last Monday of May is the 26th.
+        assert_eq!(rule.resolve(2025), ymd_to_ordinal(2025, 5, 26));
+    }
+
+    #[test]
+    fn nth_weekday_from_start_of_month_matches_us_thanksgiving() {
+        // Thanksgiving is the 4th Thursday of November; 2026's is the 26th.
+        let rule = HolidayRule::NthWeekday {
+            month: 11,
+            weekday: 3,
+            nth: 4,
+        };
+        assert_eq!(rule.resolve(2026), ymd_to_ordinal(2026, 11, 26));
+    }
+
+    #[test]
+    fn generate_returns_none_for_unknown_market() {
+        assert!(generate("Narnia", 2026, 2026).is_none());
+    }
+}
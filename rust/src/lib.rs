@@ -0,0 +1,4 @@
+pub mod calendar;
+pub mod holidays;
+pub mod python;
+pub mod recurrence;
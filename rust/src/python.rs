@@ -1,19 +1,67 @@
+use std::collections::VecDeque;
+
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyAny};
 
 use crate::calendar;
+use crate::holidays;
+use crate::recurrence::{self, BusinessDayConvention, Frequency, RecurrenceSpec};
 
 #[pyclass(name = "BusinessCalendar")]
 pub struct PyBusinessCalendar {
     inner: calendar::BusinessCalendar,
 }
 
+/// Standard Monday..Friday business week, used when `week_mask` is omitted.
+const DEFAULT_WEEK_MASK: [bool; 7] = [true, true, true, true, true, false, false];
+
+/// Sentinel returned by the `*_array` methods in place of `None`, since a
+/// NumPy integer array has no null slot.
+const NO_BUSINESS_DAY: i32 = i32::MIN;
+
+/// Read a 1-D NumPy (or other buffer-protocol) array of 32-bit signed
+/// integers into a `Vec<i32>` in one bulk copy, instead of a Python
+/// round-trip per element. Arrays of any other element type (such as
+/// NumPy's default `int64`) are rejected with an error.
+fn read_ordinals(py: Python<'_>, ordinals: &Bound<'_, PyAny>) -> PyResult<Vec<i32>> {
+    PyBuffer::<i32>::get(ordinals)?.to_vec(py)
+}
+
+fn to_numpy_i32(py: Python<'_>, values: Vec<i32>) -> PyResult<PyObject> {
+    let numpy = PyModule::import(py, "numpy")?;
+    Ok(numpy.call_method1("array", (values,))?.unbind())
+}
+
+fn to_numpy_bool(py: Python<'_>, values: Vec<bool>) -> PyResult<PyObject> {
+    let numpy = PyModule::import(py, "numpy")?;
+    let kwargs = [("dtype", "bool")].into_py_dict(py)?;
+    Ok(numpy.call_method("array", (values,), Some(&kwargs))?.unbind())
+}
+
+fn week_mask_from_pylist(week_mask: Option<Vec<bool>>) -> PyResult<[bool; 7]> {
+    match week_mask {
+        None => Ok(DEFAULT_WEEK_MASK),
+        Some(mask) => mask.try_into().map_err(|mask: Vec<bool>| {
+            PyValueError::new_err(format!(
+                "week_mask must have exactly 7 entries (Monday..Sunday), got {}",
+                mask.len()
+            ))
+        }),
+    }
+}
+
 #[pymethods]
 impl PyBusinessCalendar {
     #[new]
-    fn new(ordinals: Vec<i32>) -> Self {
-        PyBusinessCalendar {
-            inner: calendar::BusinessCalendar::new(ordinals),
-        }
+    #[pyo3(signature = (ordinals, week_mask=None, holidays=None))]
+    fn new(ordinals: Vec<i32>, week_mask: Option<Vec<bool>>, holidays: Option<Vec<i32>>) -> PyResult<Self> {
+        let week_mask = week_mask_from_pylist(week_mask)?;
+        let holidays = holidays.unwrap_or_default();
+        Ok(PyBusinessCalendar {
+            inner: calendar::BusinessCalendar::new(ordinals, week_mask, &holidays),
+        })
     }
 
     fn is_business_day(&self, ordinal: i32) -> bool {
@@ -40,6 +88,72 @@ impl PyBusinessCalendar {
         self.inner.count_business_days(start, end)
     }
 
+    /// Vectorized `is_business_day` over a 1-D NumPy integer array, read
+    /// via the buffer protocol to avoid a Python round-trip per element.
+    fn is_business_day_array(&self, py: Python<'_>, ordinals: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let ordinals = read_ordinals(py, ordinals)?;
+        let results: Vec<bool> = ordinals.iter().map(|&o| self.inner.is_business_day(o)).collect();
+        to_numpy_bool(py, results)
+    }
+
+    /// Vectorized `next_business_day`. Entries with no next business day
+    /// are reported as `NO_BUSINESS_DAY` (`i32::MIN`), since a NumPy
+    /// integer array cannot hold `None`.
+    fn next_business_day_array(&self, py: Python<'_>, ordinals: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let ordinals = read_ordinals(py, ordinals)?;
+        let results: Vec<i32> = ordinals
+            .iter()
+            .map(|&o| self.inner.next_business_day(o).unwrap_or(NO_BUSINESS_DAY))
+            .collect();
+        to_numpy_i32(py, results)
+    }
+
+    /// Vectorized `prev_business_day`, see [`next_business_day_array`] for
+    /// the `NO_BUSINESS_DAY` sentinel.
+    fn prev_business_day_array(&self, py: Python<'_>, ordinals: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let ordinals = read_ordinals(py, ordinals)?;
+        let results: Vec<i32> = ordinals
+            .iter()
+            .map(|&o| self.inner.prev_business_day(o).unwrap_or(NO_BUSINESS_DAY))
+            .collect();
+        to_numpy_i32(py, results)
+    }
+
+    /// Build a calendar for a named market (e.g. "US", "UK", "Czech") over
+    /// `start_year..=end_year`, deriving its holidays instead of requiring
+    /// the caller to maintain them.
+    #[staticmethod]
+    #[pyo3(signature = (name, start_year, end_year, week_mask=None))]
+    fn from_market(
+        name: &str,
+        start_year: i32,
+        end_year: i32,
+        week_mask: Option<Vec<bool>>,
+    ) -> PyResult<Self> {
+        let week_mask = week_mask_from_pylist(week_mask)?;
+        let holidays = holidays::generate(name, start_year, end_year)
+            .ok_or_else(|| PyValueError::new_err(format!("unknown market: {name}")))?;
+
+        let start = calendar::ymd_to_ordinal(start_year, 1, 1);
+        let end = calendar::ymd_to_ordinal(end_year, 12, 31);
+        let ordinals: Vec<i32> = (start..=end).collect();
+
+        Ok(PyBusinessCalendar {
+            inner: calendar::BusinessCalendar::new(ordinals, week_mask, &holidays),
+        })
+    }
+
+    fn count_business_days_pairs(&self, starts: Vec<i32>, ends: Vec<i32>) -> PyResult<Vec<usize>> {
+        if starts.len() != ends.len() {
+            return Err(PyValueError::new_err(format!(
+                "starts and ends must have the same length, got {} and {}",
+                starts.len(),
+                ends.len()
+            )));
+        }
+        Ok(self.inner.count_business_days_pairs(&starts, &ends))
+    }
+
     fn get_business_day_index(&self, ordinal: i32) -> Option<usize> {
         self.inner.get_index(ordinal)
     }
@@ -57,8 +171,132 @@ impl PyBusinessCalendar {
     }
 }
 
+fn parse_frequency(freq: &str) -> PyResult<Frequency> {
+    match freq.to_ascii_uppercase().as_str() {
+        "DAILY" => Ok(Frequency::Daily),
+        "WEEKLY" => Ok(Frequency::Weekly),
+        "MONTHLY" => Ok(Frequency::Monthly),
+        other => Err(PyValueError::new_err(format!(
+            "unknown frequency: {other} (expected DAILY, WEEKLY, or MONTHLY)"
+        ))),
+    }
+}
+
+fn parse_convention(convention: &str) -> PyResult<BusinessDayConvention> {
+    match convention.to_ascii_lowercase().as_str() {
+        "following" => Ok(BusinessDayConvention::Following),
+        "modifiedfollowing" | "modified_following" => Ok(BusinessDayConvention::ModifiedFollowing),
+        "preceding" => Ok(BusinessDayConvention::Preceding),
+        other => Err(PyValueError::new_err(format!(
+            "unknown business day convention: {other} (expected Following, ModifiedFollowing, or Preceding)"
+        ))),
+    }
+}
+
+/// Lazily streams recurring dates, snapped onto a `BusinessCalendar`,
+/// without materializing the whole series up front.
+#[pyclass(name = "Recurrence")]
+pub struct PyRecurrence {
+    calendar: Py<PyBusinessCalendar>,
+    convention: BusinessDayConvention,
+    spec: RecurrenceSpec,
+    period_start: i32,
+    pending: VecDeque<i32>,
+    emitted: u32,
+    done: bool,
+}
+
+#[pymethods]
+impl PyRecurrence {
+    #[new]
+    #[pyo3(signature = (
+        calendar, dtstart, freq, interval=1, count=None, until=None,
+        byweekday=None, bymonthday=None, convention="Following",
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        calendar: Py<PyBusinessCalendar>,
+        dtstart: i32,
+        freq: &str,
+        interval: i32,
+        count: Option<u32>,
+        until: Option<i32>,
+        byweekday: Option<Vec<usize>>,
+        bymonthday: Option<Vec<i32>>,
+        convention: &str,
+    ) -> PyResult<Self> {
+        let spec = RecurrenceSpec {
+            freq: parse_frequency(freq)?,
+            interval,
+            count,
+            until,
+            byweekday,
+            bymonthday,
+        };
+        spec.validate().map_err(PyValueError::new_err)?;
+        Ok(PyRecurrence {
+            calendar,
+            convention: parse_convention(convention)?,
+            spec,
+            period_start: dtstart,
+            pending: VecDeque::new(),
+            emitted: 0,
+            done: false,
+        })
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> Option<i32> {
+        if self.done {
+            return None;
+        }
+        if let Some(count) = self.spec.count {
+            if self.emitted >= count {
+                self.done = true;
+                return None;
+            }
+        }
+
+        let calendar = self.calendar.borrow(py);
+        loop {
+            if self.pending.is_empty() {
+                recurrence::fill_pending(&mut self.period_start, &self.spec, &mut self.pending);
+                if self.pending.is_empty() && recurrence::past_until(self.period_start, self.spec.until) {
+                    self.done = true;
+                    return None;
+                }
+            }
+            let Some(raw) = self.pending.pop_front() else {
+                continue;
+            };
+
+            if let Some(until) = self.spec.until {
+                if raw > until {
+                    self.done = true;
+                    return None;
+                }
+            }
+
+            if let Some(occurrence) = recurrence::snap_to_calendar(&calendar.inner, self.convention, raw) {
+                if let Some(until) = self.spec.until {
+                    if occurrence > until {
+                        self.done = true;
+                        return None;
+                    }
+                }
+                self.emitted += 1;
+                return Some(occurrence);
+            }
+        }
+    }
+}
+
 #[pymodule]
 pub fn _opendate(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyBusinessCalendar>()?;
+    m.add_class::<PyRecurrence>()?;
     Ok(())
 }
@@ -0,0 +1,323 @@
+//! Lazy recurrence-rule iteration, snapped to a [`BusinessCalendar`].
+
+use std::collections::VecDeque;
+
+use crate::calendar::{self, BusinessCalendar};
+
+/// How often occurrences recur before calendar snapping is applied.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// How an occurrence that lands on a non-business day is moved onto one.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BusinessDayConvention {
+    /// Roll forward to the next business day.
+    Following,
+    /// Roll forward, unless that crosses into the next month, in which
+    /// case roll backward instead.
+    ModifiedFollowing,
+    /// Roll backward to the previous business day.
+    Preceding,
+}
+
+/// A recurrence spec: frequency/interval plus optional bounds and filters.
+pub struct RecurrenceSpec {
+    pub freq: Frequency,
+    pub interval: i32,
+    pub count: Option<u32>,
+    pub until: Option<i32>,
+    /// Weekdays to keep (Monday = 0 .. Sunday = 6). Only meaningful for
+    /// `Daily`/`Weekly` frequencies.
+    pub byweekday: Option<Vec<usize>>,
+    /// Days of the month to keep (1-based). Only meaningful for `Monthly`.
+    pub bymonthday: Option<Vec<i32>>,
+}
+
+impl RecurrenceSpec {
+    /// Reject filters that no period can ever match (an empty list, or an
+    /// entry outside its valid range), which would otherwise make `next()`
+    /// loop forever over empty periods. A valid filter that the chosen
+    /// interval never reaches (e.g. `bymonthday = [31]` stepping only
+    /// through 30-day months) is not rejected here.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(days) = &self.byweekday {
+            if days.is_empty() {
+                return Err("byweekday must not be empty".to_string());
+            }
+            if days.iter().any(|&d| d > 6) {
+                return Err("byweekday entries must be 0..=6 (Monday..Sunday)".to_string());
+            }
+        }
+        if let Some(days) = &self.bymonthday {
+            if days.is_empty() {
+                return Err("bymonthday must not be empty".to_string());
+            }
+            if days.iter().any(|&d| !(1..=31).contains(&d)) {
+                return Err("bymonthday entries must be 1..=31".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether `period_start` has moved past `until`, meaning no further
+/// occurrence can ever be produced even though the current period's
+/// `pending` batch came back empty.
+pub fn past_until(period_start: i32, until: Option<i32>) -> bool {
+    until.is_some_and(|u| period_start > u)
+}
+
+/// Lazily generates occurrences of a [`RecurrenceSpec`], snapped onto a
+/// [`BusinessCalendar`] per a [`BusinessDayConvention`].
+///
+/// Implements `Iterator<Item = i32>`, advancing an internal counter-date on
+/// each `next()` and rejecting occurrences that don't pass the `by*`
+/// filters until a valid one is found or `until`/`count` is reached.
+pub struct RruleIterator<'a> {
+    calendar: &'a BusinessCalendar,
+    convention: BusinessDayConvention,
+    spec: RecurrenceSpec,
+    period_start: i32,
+    pending: VecDeque<i32>,
+    emitted: u32,
+    done: bool,
+}
+
+fn add_months(year: i32, month: u32, delta: i32) -> (i32, u32) {
+    let zero_based = (month as i32 - 1) + delta;
+    let year = year + zero_based.div_euclid(12);
+    let month = zero_based.rem_euclid(12) as u32 + 1;
+    (year, month)
+}
+
+/// Generate the next batch of raw (pre-snap) candidate ordinals for
+/// `period_start`, applying the `by*` filters, and advance `period_start`
+/// to the following period. Free of any `BusinessCalendar` borrow so it can
+/// be driven either by [`RruleIterator`] or directly by a caller that holds
+/// its calendar behind a reference-counted handle (e.g. the Python binding).
+pub fn fill_pending(period_start: &mut i32, spec: &RecurrenceSpec, pending: &mut VecDeque<i32>) {
+    let (year, month, day) = calendar::ordinal_to_ymd(*period_start);
+
+    match spec.freq {
+        Frequency::Daily => {
+            let matches = match &spec.byweekday {
+                Some(days) => days.contains(&calendar::weekday(*period_start)),
+                None => true,
+            };
+            if matches {
+                pending.push_back(*period_start);
+            }
+            *period_start += spec.interval;
+        }
+        Frequency::Weekly => {
+            let week_start = *period_start - calendar::weekday(*period_start) as i32;
+            let days = spec
+                .byweekday
+                .clone()
+                .unwrap_or_else(|| vec![calendar::weekday(*period_start)]);
+            let mut candidates: Vec<i32> = days
+                .into_iter()
+                .map(|wd| week_start + wd as i32)
+                .filter(|&d| d >= *period_start)
+                .collect();
+            candidates.sort_unstable();
+            pending.extend(candidates);
+            *period_start = week_start + 7 * spec.interval;
+        }
+        Frequency::Monthly => {
+            let days_in_month = calendar::days_in_month(year, month);
+            let days = spec.bymonthday.clone().unwrap_or_else(|| vec![day as i32]);
+            let mut candidates: Vec<i32> = days
+                .into_iter()
+                .filter(|&d| d >= 1 && d <= days_in_month)
+                .map(|d| calendar::ymd_to_ordinal(year, month, d as u32))
+                .filter(|&o| o >= *period_start)
+                .collect();
+            candidates.sort_unstable();
+            pending.extend(candidates);
+            let (next_year, next_month) = add_months(year, month, spec.interval);
+            *period_start = calendar::ymd_to_ordinal(next_year, next_month, 1);
+        }
+    }
+}
+
+/// Snap `ordinal` onto `calendar` per `convention`, or leave it untouched
+/// if it already is a business day.
+pub fn snap_to_calendar(calendar: &BusinessCalendar, convention: BusinessDayConvention, ordinal: i32) -> Option<i32> {
+    if calendar.is_business_day(ordinal) {
+        return Some(ordinal);
+    }
+    match convention {
+        BusinessDayConvention::Following => calendar.next_business_day(ordinal),
+        BusinessDayConvention::Preceding => calendar.prev_business_day(ordinal),
+        BusinessDayConvention::ModifiedFollowing => {
+            let following = calendar.next_business_day(ordinal)?;
+            let (_, m1, _) = calendar::ordinal_to_ymd(ordinal);
+            let (_, m2, _) = calendar::ordinal_to_ymd(following);
+            if m1 == m2 {
+                Some(following)
+            } else {
+                calendar.prev_business_day(ordinal)
+            }
+        }
+    }
+}
+
+impl<'a> RruleIterator<'a> {
+    pub fn new(
+        calendar: &'a BusinessCalendar,
+        dtstart: i32,
+        spec: RecurrenceSpec,
+        convention: BusinessDayConvention,
+    ) -> Result<Self, String> {
+        spec.validate()?;
+        Ok(RruleIterator {
+            calendar,
+            convention,
+            spec,
+            period_start: dtstart,
+            pending: VecDeque::new(),
+            emitted: 0,
+            done: false,
+        })
+    }
+}
+
+impl<'a> Iterator for RruleIterator<'a> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        if self.done {
+            return None;
+        }
+        if let Some(count) = self.spec.count {
+            if self.emitted >= count {
+                self.done = true;
+                return None;
+            }
+        }
+
+        loop {
+            if self.pending.is_empty() {
+                fill_pending(&mut self.period_start, &self.spec, &mut self.pending);
+                if self.pending.is_empty() && past_until(self.period_start, self.spec.until) {
+                    self.done = true;
+                    return None;
+                }
+            }
+            let Some(raw) = self.pending.pop_front() else {
+                continue;
+            };
+
+            if let Some(until) = self.spec.until {
+                if raw > until {
+                    self.done = true;
+                    return None;
+                }
+            }
+
+            if let Some(occurrence) = snap_to_calendar(self.calendar, self.convention, raw) {
+                if let Some(until) = self.spec.until {
+                    if occurrence > until {
+                        self.done = true;
+                        return None;
+                    }
+                }
+                self.emitted += 1;
+                return Some(occurrence);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::BusinessCalendar;
+
+    #[test]
+    fn monthly_occurrences_never_precede_dtstart() {
+        let week_mask = [true, true, true, true, true, false, false];
+        let dtstart = calendar::ymd_to_ordinal(2026, 3, 15);
+        let days: Vec<i32> = (dtstart - 60..dtstart + 60).collect();
+        let cal = BusinessCalendar::new(days, week_mask, &[]);
+
+        let spec = RecurrenceSpec {
+            freq: Frequency::Monthly,
+            interval: 1,
+            count: Some(4),
+            until: None,
+            byweekday: None,
+            bymonthday: Some(vec![1, 15]),
+        };
+        let occurrences: Vec<i32> =
+            RruleIterator::new(&cal, dtstart, spec, BusinessDayConvention::Following)
+                .unwrap()
+                .collect();
+
+        assert!(occurrences.iter().all(|&o| o >= dtstart));
+    }
+
+    #[test]
+    fn new_rejects_filters_that_can_never_match() {
+        let week_mask = [true, true, true, true, true, false, false];
+        let dtstart = calendar::ymd_to_ordinal(2026, 3, 15);
+        let days: Vec<i32> = (dtstart..dtstart + 10).collect();
+        let cal = BusinessCalendar::new(days, week_mask, &[]);
+
+        let empty_byweekday = RecurrenceSpec {
+            freq: Frequency::Weekly,
+            interval: 1,
+            count: None,
+            until: None,
+            byweekday: Some(vec![]),
+            bymonthday: None,
+        };
+        assert!(RruleIterator::new(&cal, dtstart, empty_byweekday, BusinessDayConvention::Following).is_err());
+
+        let out_of_range_bymonthday = RecurrenceSpec {
+            freq: Frequency::Monthly,
+            interval: 1,
+            count: None,
+            until: None,
+            byweekday: None,
+            bymonthday: Some(vec![35]),
+        };
+        assert!(
+            RruleIterator::new(&cal, dtstart, out_of_range_bymonthday, BusinessDayConvention::Following).is_err()
+        );
+    }
+
+    #[test]
+    fn next_terminates_when_a_period_never_produces_a_candidate() {
+        // interval=12 on a Monthly spec always lands back on April (30
+        // days), so bymonthday=[31] never matches any period even though
+        // the spec itself passes `validate`. Without bounding the loop
+        // against `until` when `fill_pending` comes back empty, this used
+        // to spin forever.
+        let week_mask = [true, true, true, true, true, false, false];
+        let dtstart = calendar::ymd_to_ordinal(2026, 4, 1);
+        let until = calendar::ymd_to_ordinal(2076, 4, 1);
+        let days: Vec<i32> = (dtstart..until).collect();
+        let cal = BusinessCalendar::new(days, week_mask, &[]);
+
+        let spec = RecurrenceSpec {
+            freq: Frequency::Monthly,
+            interval: 12,
+            count: None,
+            until: Some(until),
+            byweekday: None,
+            bymonthday: Some(vec![31]),
+        };
+        let occurrences: Vec<i32> =
+            RruleIterator::new(&cal, dtstart, spec, BusinessDayConvention::Following)
+                .unwrap()
+                .collect();
+
+        assert!(occurrences.is_empty());
+    }
+}